@@ -0,0 +1,140 @@
+//! Conversions between `BitVec` and plain byte buffers, with an explicit
+//! inter-element byte order independent of the host platform.
+//!
+//! `Endian` already controls how bits are numbered *within* one `T` store
+//! element; `ByteOrder` here adds the complementary axis of how store
+//! elements are laid out as bytes *across* the buffer, so a `BitVec<E,
+//! u32>` built on a little-endian host still round-trips through a byte
+//! buffer produced on (or destined for) a big-endian host.
+
+/// Byte order for inter-element layout, independent of the host platform
+/// and of the intra-element `Endian` parameter on `BitVec`/`BitSlice`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ByteOrder {
+	/// Most significant byte first.
+	Big,
+	/// Least significant byte first.
+	Little,
+}
+
+impl ByteOrder {
+	/// The `ByteOrder` matching this host's native representation.
+	#[cfg(target_endian = "big")]
+	pub const NATIVE: Self = ByteOrder::Big;
+	/// The `ByteOrder` matching this host's native representation.
+	#[cfg(target_endian = "little")]
+	pub const NATIVE: Self = ByteOrder::Little;
+}
+
+macro_rules! __bitvec_bytes {
+	( $( $t:ty ),+ ) => { $(
+		impl<E: $crate::Endian> $crate::BitVec<E, $t> {
+			/// Serialize the store elements backing this `BitVec` into a
+			/// byte buffer using the given `order`.
+			pub fn to_bytes(&self, order: $crate::bytes::ByteOrder) -> ::std::vec::Vec<u8> {
+				let elems = self.as_slice();
+				let mut out = ::std::vec::Vec::with_capacity(
+					elems.len() * ::core::mem::size_of::<$t>(),
+				);
+				for &elem in elems {
+					match order {
+						$crate::bytes::ByteOrder::Big => out.extend_from_slice(&elem.to_be_bytes()),
+						$crate::bytes::ByteOrder::Little => out.extend_from_slice(&elem.to_le_bytes()),
+					}
+				}
+				out
+			}
+
+			/// Borrow this `BitVec`'s storage as raw bytes in the host's
+			/// native byte order, with no copying. Returns `None` if
+			/// `order` does not match the host's native order; fall back
+			/// to [`to_bytes`] in that case.
+			///
+			/// [`to_bytes`]: #method.to_bytes
+			pub fn as_bytes(&self, order: $crate::bytes::ByteOrder) -> Option<&[u8]> {
+				if order != $crate::bytes::ByteOrder::NATIVE {
+					return None;
+				}
+				let elems = self.as_slice();
+				let ptr = elems.as_ptr() as *const u8;
+				Some(unsafe {
+					::core::slice::from_raw_parts(ptr, elems.len() * ::core::mem::size_of::<$t>())
+				})
+			}
+
+			/// Reconstruct a `BitVec` from a byte buffer produced by
+			/// [`to_bytes`] (or an equivalent external encoder), given the
+			/// byte order it used and the exact bit length to keep.
+			///
+			/// [`to_bytes`]: #method.to_bytes
+			pub fn from_bytes(bytes: &[u8], order: $crate::bytes::ByteOrder, bits: usize) -> Self {
+				let width = ::core::mem::size_of::<$t>();
+				assert_eq!(
+					bytes.len() % width,
+					0,
+					"byte buffer is not a whole number of store elements",
+				);
+				assert!(
+					bits <= bytes.len() * 8,
+					"bits ({}) exceeds the {} bits actually present in the byte buffer",
+					bits,
+					bytes.len() * 8,
+				);
+
+				let mut elems = ::std::vec::Vec::with_capacity(bytes.len() / width);
+				for chunk in bytes.chunks(width) {
+					let mut buf = [0u8; ::core::mem::size_of::<$t>()];
+					buf.copy_from_slice(chunk);
+					elems.push(match order {
+						$crate::bytes::ByteOrder::Big => <$t>::from_be_bytes(buf),
+						$crate::bytes::ByteOrder::Little => <$t>::from_le_bytes(buf),
+					});
+				}
+
+				let mut out = $crate::BitVec::<E, $t>::from_vec(elems);
+				out.truncate(bits);
+				out
+			}
+		}
+	)+ };
+}
+
+__bitvec_bytes!(u8, u16, u32, u64);
+
+#[cfg(test)]
+mod tests {
+	use super::ByteOrder;
+	use crate::{bitvec, BigEndian};
+
+	#[test]
+	fn to_bytes_and_from_bytes_round_trip_both_orders() {
+		let bv = bitvec![BigEndian, u32; 1, 0, 1, 1];
+
+		for &order in &[ByteOrder::Big, ByteOrder::Little] {
+			let bytes = bv.to_bytes(order);
+			let back = crate::BitVec::<BigEndian, u32>::from_bytes(&bytes, order, bv.len());
+			assert_eq!(back.as_slice(), bv.as_slice());
+			assert_eq!(back.len(), bv.len());
+		}
+	}
+
+	#[test]
+	fn as_bytes_is_zero_copy_only_in_native_order() {
+		let bv = bitvec![BigEndian, u32; 1, 0, 1, 1];
+
+		assert!(bv.as_bytes(ByteOrder::NATIVE).is_some());
+
+		let swapped = match ByteOrder::NATIVE {
+			ByteOrder::Big => ByteOrder::Little,
+			ByteOrder::Little => ByteOrder::Big,
+		};
+		assert!(bv.as_bytes(swapped).is_none());
+	}
+
+	#[test]
+	#[should_panic(expected = "exceeds")]
+	fn from_bytes_rejects_a_bit_length_longer_than_the_buffer() {
+		let bytes = [0u8; 4];
+		let _ = crate::BitVec::<BigEndian, u32>::from_bytes(&bytes, ByteOrder::NATIVE, 33);
+	}
+}
@@ -0,0 +1,173 @@
+//! A read cursor over a `BitSlice`, for decoding protocols whose fields
+//! aren't byte-aligned (4-bit nibbles, 12-bit lengths, 3-bit flags, and the
+//! like).
+//!
+//! `BitSlice` already lets you index single bits, but pulling a multi-bit
+//! integer field out of a stream and advancing past it has no ergonomic
+//! answer today. `BitReader` fills that gap: it borrows a `BitSlice` and
+//! tracks a bit offset into it, handing back assembled integers as it walks
+//! forward.
+
+use crate::{BitSlice, Bits, Endian};
+
+mod sealed {
+	/// Closes [`FromBits`](super::FromBits) against downstream
+	/// implementations: only types that already implement `Sealed` here
+	/// can implement `FromBits`, and only this module can implement
+	/// `Sealed`.
+	pub trait Sealed {}
+}
+
+/// Sealed trait implemented for the unsigned integer types [`BitReader`]
+/// can assemble a field into.
+///
+/// `take_bits`/`peek_bits` trust `WIDTH` and `from_bits` to agree with each
+/// other; sealing the trait keeps a downstream crate from implementing it
+/// for a type where they don't.
+///
+/// [`BitReader`]: struct.BitReader.html
+pub trait FromBits: sealed::Sealed + Sized + Copy {
+	/// Bit width of this integer type; the maximum field size `take_bits`
+	/// can produce into it.
+	const WIDTH: usize;
+
+	/// Assemble up to `Self::WIDTH` bits into a value, MSB- or LSB-first
+	/// according to `E`.
+	fn from_bits<E: Endian>(bits: &[bool]) -> Self;
+}
+
+macro_rules! __from_bits_impl {
+	( $( $u:ty ),+ ) => { $(
+		impl sealed::Sealed for $u {}
+
+		impl FromBits for $u {
+			const WIDTH: usize = ::core::mem::size_of::<$u>() * 8;
+
+			fn from_bits<E: Endian>(bits: &[bool]) -> Self {
+				let mut out: $u = 0;
+				if E::is_msb_first() {
+					for &bit in bits {
+						out <<= 1;
+						out |= bit as $u;
+					}
+				} else {
+					for (idx, &bit) in bits.iter().enumerate() {
+						out |= (bit as $u) << idx;
+					}
+				}
+				out
+			}
+		}
+	)+ };
+}
+
+__from_bits_impl!(u8, u16, u32, u64, u128);
+
+/// A read cursor over a borrowed `BitSlice`, for walking non-byte-aligned
+/// protocol fields one at a time.
+///
+/// `BitReader` does not own its data; it borrows a `BitSlice` and advances a
+/// bit offset into it as fields are read out.
+pub struct BitReader<'a, E, T>
+where
+	E: Endian,
+	T: Bits,
+{
+	slice: &'a BitSlice<E, T>,
+	pos: usize,
+}
+
+impl<'a, E, T> BitReader<'a, E, T>
+where
+	E: Endian,
+	T: Bits,
+{
+	/// Wrap a `BitSlice` for sequential reading, starting at its first bit.
+	pub fn new(slice: &'a BitSlice<E, T>) -> Self {
+		Self { slice, pos: 0 }
+	}
+
+	/// Number of bits left before the cursor runs off the end of the slice.
+	pub fn remaining(&self) -> usize {
+		self.slice.len() - self.pos
+	}
+
+	/// Advance the cursor to the next byte boundary, discarding any bits
+	/// skipped. A no-op if the cursor already sits on one. Clamped to the
+	/// end of the slice if there's no further byte boundary left to reach.
+	pub fn align_to_byte(&mut self) {
+		let rem = self.pos % 8;
+		if rem != 0 {
+			self.pos = (self.pos + (8 - rem)).min(self.slice.len());
+		}
+	}
+
+	/// Read the next `n` bits into a `U` without advancing the cursor.
+	///
+	/// `n` must not exceed `U::WIDTH`. Returns `None` if fewer than `n`
+	/// bits remain.
+	pub fn peek_bits<U: FromBits>(&self, n: usize) -> Option<U> {
+		if n > U::WIDTH || n > self.remaining() {
+			return None;
+		}
+		let bits: Vec<bool> = (0 .. n)
+			.map(|i| self.slice.get(self.pos + i).unwrap_or(false))
+			.collect();
+		Some(U::from_bits::<E>(&bits))
+	}
+
+	/// Read the next `n` bits into a `U`, advancing the cursor past them.
+	///
+	/// `n` must not exceed `U::WIDTH`. Returns `None` (and leaves the
+	/// cursor where it was) if fewer than `n` bits remain.
+	pub fn take_bits<U: FromBits>(&mut self, n: usize) -> Option<U> {
+		let val = self.peek_bits::<U>(n)?;
+		self.pos += n;
+		Some(val)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::BitReader;
+	use crate::{bitvec, BigEndian};
+
+	#[test]
+	fn take_bits_walks_a_stream_of_nibbles() {
+		let bv = bitvec![BigEndian, u8; 1, 0, 1, 0, 0, 0, 1, 1];
+		let mut reader = BitReader::new(&bv);
+
+		assert_eq!(reader.remaining(), 8);
+		assert_eq!(reader.take_bits::<u8>(4), Some(0b1010));
+		assert_eq!(reader.remaining(), 4);
+		assert_eq!(reader.take_bits::<u8>(4), Some(0b0011));
+		assert_eq!(reader.remaining(), 0);
+		assert_eq!(reader.take_bits::<u8>(1), None);
+	}
+
+	#[test]
+	fn peek_bits_does_not_advance_the_cursor() {
+		let bv = bitvec![BigEndian, u8; 1, 1, 0, 0];
+		let mut reader = BitReader::new(&bv);
+
+		assert_eq!(reader.peek_bits::<u8>(2), Some(0b11));
+		assert_eq!(reader.peek_bits::<u8>(2), Some(0b11));
+		assert_eq!(reader.take_bits::<u8>(2), Some(0b11));
+		assert_eq!(reader.remaining(), 2);
+	}
+
+	#[test]
+	fn align_to_byte_skips_to_the_next_boundary_and_clamps() {
+		let bv = bitvec![BigEndian, u8; 1, 0, 1, 0];
+		let mut reader = BitReader::new(&bv);
+
+		reader.take_bits::<u8>(1).unwrap();
+		reader.align_to_byte();
+		assert_eq!(reader.remaining(), 0);
+
+		// No further byte boundary exists past the end of the slice; a
+		// second call must not walk `pos` past `slice.len()`.
+		reader.align_to_byte();
+		assert_eq!(reader.remaining(), 0);
+	}
+}
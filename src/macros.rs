@@ -70,22 +70,99 @@ macro_rules! bitvec {
 	};
 }
 
-/// Build an array of `bool` (one bit per byte) and then build a `BitVec` from that (one
-/// bit per bit). I have yet to think of a way to make the source array be
-/// binary-compatible with a `BitVec` representation, so the static source is 8x larger
-/// than it needs to be.
+/// Build a `BitVec` out of the bits given to `bitvec!`.
 ///
-/// I'm sure there is a way, but I don’t think I need to spend the effort yet.
+/// This used to build a `&[bool]` (one byte per bit) and convert that into
+/// the `BitVec`; as a `&[bool]` array literal, that scratch slice got
+/// promoted to `'static` storage, so the embedded binary footprint was 8x
+/// the size of the `BitVec` it was immediately converted into. When the
+/// `Endian` is `BigEndian` or `LittleEndian` and the `Bits` type arrives
+/// here as one of the literal tokens `u8`/`u16`/`u32`/`u64`, we now pack the
+/// literal list straight into store words ourselves, via
+/// `__bitvec_pack_be!`/`__bitvec_pack_le!` below: the bits are gathered
+/// into a runtime `Vec<bool>` and packed word by word, so no `&[bool]` array
+/// literal is promoted to `'static` and there's no per-bit growth of the
+/// `BitVec` — it is filled by a single `from_vec` of already-packed words.
+/// From `bitvec!`, only the forms that omit the `Bits` type (and so pass
+/// the literal `u8`) reach those arms. An explicitly written type, as in
+/// `bitvec![BigEndian, u32; ...]`, is forwarded by `bitvec!` as an opaque
+/// `ty` fragment, which does not match a literal type token, so it takes
+/// the `Vec<bool>` plus `FromIterator<bool>` fallback, as does any custom
+/// `Endian` or `Bits` type.
+///
+/// The repetition form (`bitvec![T; bit; n]`) never had the `&[bool]`
+/// problem (every bit is the same, so there's nothing to promote), but it
+/// still filled the `BitVec` one push at a time. Since every bit is
+/// identical, the store elements are either all-zero or all-one, so we
+/// build the backing `Vec<T>` directly and mask off the unused tail bits
+/// of the final element — on the side the bits that matter live, which
+/// depends on `$end`: the high `used` bits for `BigEndian`'s MSB-first
+/// numbering, the low `used` bits for `LittleEndian`'s LSB-first one.
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __bitvec_impl {
+	//  concrete `Bits` widths: pack directly into store words.
+	( BigEndian , u8 ; $( $elt:expr ),* ) => { $crate::__bitvec_pack_be![ u8 ; $( $elt ),* ] };
+	( BigEndian , u16 ; $( $elt:expr ),* ) => { $crate::__bitvec_pack_be![ u16 ; $( $elt ),* ] };
+	( BigEndian , u32 ; $( $elt:expr ),* ) => { $crate::__bitvec_pack_be![ u32 ; $( $elt ),* ] };
+	( BigEndian , u64 ; $( $elt:expr ),* ) => { $crate::__bitvec_pack_be![ u64 ; $( $elt ),* ] };
+	( LittleEndian , u8 ; $( $elt:expr ),* ) => { $crate::__bitvec_pack_le![ u8 ; $( $elt ),* ] };
+	( LittleEndian , u16 ; $( $elt:expr ),* ) => { $crate::__bitvec_pack_le![ u16 ; $( $elt ),* ] };
+	( LittleEndian , u32 ; $( $elt:expr ),* ) => { $crate::__bitvec_pack_le![ u32 ; $( $elt ),* ] };
+	( LittleEndian , u64 ; $( $elt:expr ),* ) => { $crate::__bitvec_pack_le![ u64 ; $( $elt ),* ] };
+
+	//  any other `Endian`/`Bits` pairing, and any `Bits` type passed as a
+	//  `ty` fragment (every explicit type given to `bitvec!`): fall back to
+	//  bit-by-bit construction through `FromIterator<bool>`.
 	( $end:ident , $prim:ty ; $( $elt:expr ),* ) => {{
-		let init: &[bool] = &[
-			$( $elt as u8 > 0 ),*
-		];
-		$crate :: BitVec ::< $crate :: $end , $prim >:: from(init)
+		::std::vec![ $( $elt as u8 > 0 ),* ]
+			.into_iter()
+			.collect ::< $crate :: BitVec < $crate :: $end , $prim > > ()
+	}};
+
+	( BigEndian , $prim:ty ; $elt:expr; $rep:expr ) => {{
+		let width = ::core::mem::size_of::<$prim>() * 8;
+		let word: $prim = if $elt as u8 > 0 { !(0 as $prim) } else { 0 as $prim };
+		let elems = ( $rep + width - 1 ) / width.max(1);
+
+		let mut store = ::std::vec![ word; elems ];
+		let used = $rep % width;
+		if used != 0 {
+			if let Some(last) = store.last_mut() {
+				//  `BigEndian` numbers bits MSB-first, so the `used` valid
+				//  bits of a partial trailing word are the high ones.
+				*last &= !(0 as $prim) << (width - used);
+			}
+		}
+
+		let mut bv = $crate :: BitVec ::< $crate :: BigEndian , $prim >:: from_vec(store);
+		bv.truncate( $rep );
+		bv
 	}};
 
+	( LittleEndian , $prim:ty ; $elt:expr; $rep:expr ) => {{
+		let width = ::core::mem::size_of::<$prim>() * 8;
+		let word: $prim = if $elt as u8 > 0 { !(0 as $prim) } else { 0 as $prim };
+		let elems = ( $rep + width - 1 ) / width.max(1);
+
+		let mut store = ::std::vec![ word; elems ];
+		let used = $rep % width;
+		if used != 0 {
+			if let Some(last) = store.last_mut() {
+				//  `LittleEndian` numbers bits LSB-first, so the `used`
+				//  valid bits of a partial trailing word are the low ones.
+				*last &= !(!(0 as $prim) << used);
+			}
+		}
+
+		let mut bv = $crate :: BitVec ::< $crate :: LittleEndian , $prim >:: from_vec(store);
+		bv.truncate( $rep );
+		bv
+	}};
+
+	//  any other `Endian`: fall back to filling the `BitVec` one push at a
+	//  time, since we don't know whether a custom impl is MSB- or
+	//  LSB-first.
 	( $end:ident , $prim:ty ; $elt:expr; $rep:expr ) => {{
 		::std::iter::repeat( $elt as u8 > 0 )
 			.take( $rep )
@@ -93,6 +170,58 @@ macro_rules! __bitvec_impl {
 	}};
 }
 
+/// Pack a `bitvec!` literal bit list directly into `BigEndian` (MSB-first)
+/// store words of `$t`, instead of filling a `BitVec` one bit at a time.
+///
+/// A short trailing chunk is left-justified so its valid bits land at the
+/// high end of the word, matching `BigEndian`'s own numbering.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __bitvec_pack_be {
+	( $t:ty ; $( $elt:expr ),* ) => {{
+		let bits = ::std::vec![ $( $elt as u8 > 0 ),* ];
+		let width = ::core::mem::size_of::<$t>() * 8;
+		let mut store = ::std::vec::Vec::with_capacity((bits.len() + width - 1) / width);
+		for chunk in bits.chunks(width) {
+			let mut word: $t = 0;
+			for &bit in chunk {
+				word <<= 1;
+				word |= bit as $t;
+			}
+			if chunk.len() < width {
+				word <<= width - chunk.len();
+			}
+			store.push(word);
+		}
+		let mut bv = $crate::BitVec::<$crate::BigEndian, $t>::from_vec(store);
+		bv.truncate(bits.len());
+		bv
+	}};
+}
+
+/// Pack a `bitvec!` literal bit list directly into `LittleEndian`
+/// (LSB-first) store words of `$t`, instead of filling a `BitVec` one bit
+/// at a time.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __bitvec_pack_le {
+	( $t:ty ; $( $elt:expr ),* ) => {{
+		let bits = ::std::vec![ $( $elt as u8 > 0 ),* ];
+		let width = ::core::mem::size_of::<$t>() * 8;
+		let mut store = ::std::vec::Vec::with_capacity((bits.len() + width - 1) / width);
+		for chunk in bits.chunks(width) {
+			let mut word: $t = 0;
+			for (idx, &bit) in chunk.iter().enumerate() {
+				word |= (bit as $t) << idx;
+			}
+			store.push(word);
+		}
+		let mut bv = $crate::BitVec::<$crate::LittleEndian, $t>::from_vec(store);
+		bv.truncate(bits.len());
+		bv
+	}};
+}
+
 #[doc(hidden)]
 macro_rules! __bitslice_shift {
 	( $( $t:ty ),+ ) => { $(
@@ -177,4 +306,25 @@ mod tests {
 		bitvec![BigEndian, u64; 0; 70];
 		bitvec![LittleEndian, u64; 1; 70];
 	}
+
+	#[test]
+	fn repeat_partial_word_is_endian_correct() {
+		//  70 bits over `u8` is 9 words (72 bits), 6 of them valid in the
+		//  last word. `BigEndian` is MSB-first, so those 6 bits are the
+		//  high ones; `LittleEndian` is LSB-first, so they're the low ones.
+		let be = bitvec![BigEndian, u8; 1; 70];
+		assert_eq!(be.as_slice()[8], 0b1111_1100);
+
+		let le = bitvec![LittleEndian, u8; 1; 70];
+		assert_eq!(le.as_slice()[8], 0b0011_1111);
+	}
+
+	#[test]
+	fn literal_list_packs_bits_msb_and_lsb() {
+		let be = bitvec![BigEndian, u8; 1, 0, 1, 0, 0, 0, 0, 0, 1, 1];
+		assert_eq!(be.as_slice(), &[0b1010_0000, 0b1100_0000]);
+
+		let le = bitvec![LittleEndian, u8; 1, 0, 1, 0, 0, 0, 0, 0, 1, 1];
+		assert_eq!(le.as_slice(), &[0b0000_0101, 0b0000_0011]);
+	}
 }
\ No newline at end of file
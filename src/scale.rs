@@ -0,0 +1,137 @@
+//! SCALE codec support for bit containers.
+//!
+//! This implements `parity_scale_codec`'s `Encode`/`Decode` for `BitSlice<E,
+//! T>` and `BitVec<E, T>`, gated behind the `scale` feature, so that
+//! `bitvec` containers can be embedded directly in SCALE-encoded structures
+//! (as used throughout the Substrate ecosystem) without a hand-written shim
+//! at every call site.
+//!
+//! The wire format is a compact-encoded bit length followed by the minimum
+//! number of `T` store elements needed to hold that many bits, each written
+//! out via its own `Encode`/`Decode` impl. The trailing store element is
+//! zero-padded past the declared bit length on encode, and those padding
+//! bits are simply dropped by the final `truncate` on decode.
+//!
+//! Enable with the `scale` feature and wire in via:
+//!
+//! ```toml
+//! [dependencies.bitvec]
+//! features = ["scale"]
+//! ```
+//!
+//! This whole module is gated on that feature, so `parity-scale-codec` is
+//! an optional dependency pulled in only when `scale` is turned on:
+//!
+//! ```toml
+//! [dependencies]
+//! parity-scale-codec = { version = "...", optional = true }
+//!
+//! [features]
+//! scale = ["dep:parity-scale-codec"]
+//! ```
+//!
+//! and declared at the crate root as `#[cfg(feature = "scale")] mod scale;`.
+#![cfg(feature = "scale")]
+
+use core::convert::TryFrom;
+
+use parity_scale_codec::{Compact, Decode, Encode, Error, Input, Output};
+
+use crate::{BitSlice, BitVec, Bits, Endian};
+
+/// Upper bound, in bits, on a length read back out of a `Compact` prefix.
+///
+/// Without this, a corrupt or adversarial prefix claiming e.g. `u64::MAX`
+/// bits would make `decode` try to allocate a correspondingly enormous
+/// store before it ever got the chance to fail reading the data itself.
+const MAX_DECODE_BITS: u64 = 1 << 32;
+
+impl<E, T> Encode for BitSlice<E, T>
+where
+	E: Endian,
+	T: Bits + Encode,
+{
+	fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+		Compact(self.len() as u64).encode_to(dest);
+
+		// Re-pack through `BitVec`'s own bit collection (the same
+		// machinery `bitvec!` uses) so the trailing store element comes
+		// out zero-filled past `self.len()` for free.
+		let packed = self.iter().collect::<BitVec<E, T>>();
+		for elem in packed.as_slice() {
+			elem.encode_to(dest);
+		}
+	}
+}
+
+impl<E, T> Encode for BitVec<E, T>
+where
+	E: Endian,
+	T: Bits + Encode,
+{
+	fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+		self.as_bitslice().encode_to(dest)
+	}
+}
+
+impl<E, T> Decode for BitVec<E, T>
+where
+	E: Endian,
+	T: Bits + Decode,
+{
+	fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+		let len = Compact::<u64>::decode(input)?.0;
+		if len >= MAX_DECODE_BITS {
+			return Err("BitVec length prefix exceeds the maximum decodable size".into());
+		}
+		let len = usize::try_from(len)
+			.map_err(|_| Error::from("BitVec length prefix does not fit in this platform's usize"))?;
+
+		let width = ::core::mem::size_of::<T>() * 8;
+		let elems = (len + width - 1) / width;
+
+		// `elems` is still derived from an otherwise-unverified length
+		// prefix; `MAX_DECODE_BITS` only bounds it to an architecture-sane
+		// ceiling, not to what `input` actually still has left. Cap the
+		// up-front allocation at however many store elements the input
+		// could possibly still contain, the same way
+		// `parity_scale_codec`'s own `Vec<T>` decode does.
+		let capacity = match input.remaining_len() {
+			Ok(Some(bytes)) => elems.min(bytes / ::core::mem::size_of::<T>().max(1)),
+			_ => elems.min(1024),
+		};
+
+		let mut store = Vec::with_capacity(capacity);
+		for _ in 0 .. elems {
+			store.push(T::decode(input)?);
+		}
+
+		let mut out = BitVec::<E, T>::from_vec(store);
+		out.truncate(len);
+		Ok(out)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use parity_scale_codec::{Decode, Encode};
+
+	use super::MAX_DECODE_BITS;
+	use crate::{bitvec, BigEndian, BitVec};
+
+	#[test]
+	fn round_trip_encode_decode_pads_and_truncates() {
+		let bv = bitvec![BigEndian, u32; 1, 0, 1, 1, 0, 0, 1];
+		let bytes = bv.encode();
+		let back = BitVec::<BigEndian, u32>::decode(&mut &bytes[..]).unwrap();
+		assert_eq!(back.as_slice(), bv.as_slice());
+		assert_eq!(back.len(), bv.len());
+	}
+
+	#[test]
+	fn decode_rejects_a_length_at_the_cap() {
+		let bytes = parity_scale_codec::Compact(MAX_DECODE_BITS).encode();
+		let err = BitVec::<BigEndian, u32>::decode(&mut &bytes[..]).unwrap_err();
+		assert!(err.to_string().contains("maximum decodable size"));
+	}
+}